@@ -0,0 +1,160 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use super::ObservableProperty;
+
+/// Keeps `target` synchronized with `source`: whenever `source` changes, `target` is
+/// updated to `convert(&source value)`.
+///
+/// `target` is synchronized immediately with `source`'s current value, then again on
+/// every subsequent `PropertyChanged`. Passing `Clone::clone` as `convert` binds two
+/// properties of the same type; any other closure lets a `Property<A>` bind to a
+/// `Property<B>`.
+///
+/// # Examples
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use mvvm::System::ComponentModel::{bind_one_way, ObservableProperty};
+///
+/// let mut source = ObservableProperty::new(1);
+/// let target = Rc::new(RefCell::new(ObservableProperty::new(0)));
+///
+/// bind_one_way(&mut source, Rc::clone(&target), |v| v * 10);
+/// assert_eq!(target.borrow().get(), 10);
+///
+/// source.set(2);
+/// assert_eq!(target.borrow().get(), 20);
+/// ```
+pub fn bind_one_way<'a, A, B>(
+    source: &mut ObservableProperty<'a, A>,
+    target: Rc<RefCell<ObservableProperty<'a, B>>>,
+    convert: impl Fn(&A) -> B + 'a,
+) where
+    A: Eq + Default + Clone,
+    B: Eq + Default + Clone + 'a,
+{
+    target.borrow_mut().set(convert(&source.get()));
+
+    source.PropertyChanged.add(move |p| {
+        target.borrow_mut().set(convert(&p.get()));
+    });
+}
+
+/// Keeps `a` and `b` synchronized in both directions, converting between their values
+/// with `a_to_b` and `b_to_a`.
+///
+/// `b` is synchronized to `a`'s current value immediately, then both properties are
+/// cross-subscribed to each other's `PropertyChanged`. A shared reentrancy guard
+/// suppresses the echoed update a write triggers in its peer, so a change to one side
+/// propagates to the other exactly once instead of bouncing back and forth forever.
+///
+/// # Examples
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use mvvm::System::ComponentModel::{bind_two_way, ObservableProperty};
+///
+/// let a = Rc::new(RefCell::new(ObservableProperty::new(1)));
+/// let b = Rc::new(RefCell::new(ObservableProperty::new(0)));
+///
+/// bind_two_way(Rc::clone(&a), Rc::clone(&b), |v| v * 10, |v| v / 10);
+/// assert_eq!(b.borrow().get(), 10);
+///
+/// a.borrow_mut().set(2);
+/// assert_eq!(b.borrow().get(), 20);
+///
+/// b.borrow_mut().set(30);
+/// assert_eq!(a.borrow().get(), 3);
+/// ```
+pub fn bind_two_way<'a, A, B>(
+    a: Rc<RefCell<ObservableProperty<'a, A>>>,
+    b: Rc<RefCell<ObservableProperty<'a, B>>>,
+    a_to_b: impl Fn(&A) -> B + 'a,
+    b_to_a: impl Fn(&B) -> A + 'a,
+) where
+    A: Eq + Default + Clone + 'a,
+    B: Eq + Default + Clone + 'a,
+{
+    let guard = Rc::new(Cell::new(false));
+
+    b.borrow_mut().set(a_to_b(&a.borrow().get()));
+
+    {
+        let b = Rc::clone(&b);
+        let guard = Rc::clone(&guard);
+        a.borrow_mut().PropertyChanged.add(move |p| {
+            if guard.get() {
+                return;
+            }
+            guard.set(true);
+            b.borrow_mut().set(a_to_b(&p.get()));
+            guard.set(false);
+        });
+    }
+    {
+        let a = Rc::clone(&a);
+        b.borrow_mut().PropertyChanged.add(move |p| {
+            if guard.get() {
+                return;
+            }
+            guard.set(true);
+            a.borrow_mut().set(b_to_a(&p.get()));
+            guard.set(false);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    #[test]
+    fn test_bind_one_way() {
+        let mut source = ObservableProperty::new(1);
+        let target = Rc::new(RefCell::new(ObservableProperty::new(0)));
+
+        bind_one_way(&mut source, Rc::clone(&target), |v| v * 10);
+        assert_eq!(target.borrow().get(), 10);
+
+        source.set(2);
+        assert_eq!(target.borrow().get(), 20);
+    }
+
+    #[test]
+    fn test_bind_two_way() {
+        let a = Rc::new(RefCell::new(ObservableProperty::new(1)));
+        let b = Rc::new(RefCell::new(ObservableProperty::new(0)));
+
+        bind_two_way(Rc::clone(&a), Rc::clone(&b), |v| v * 10, |v| v / 10);
+        assert_eq!(b.borrow().get(), 10);
+
+        a.borrow_mut().set(2);
+        assert_eq!(b.borrow().get(), 20);
+
+        b.borrow_mut().set(30);
+        assert_eq!(a.borrow().get(), 3);
+    }
+
+    #[test]
+    fn test_bind_two_way_does_not_loop() {
+        let a = Rc::new(RefCell::new(ObservableProperty::new(1)));
+        let b = Rc::new(RefCell::new(ObservableProperty::new(0)));
+
+        let a_changes = Rc::new(RefCell::new(0));
+        {
+            let a_changes = Rc::clone(&a_changes);
+            a.borrow_mut()
+                .PropertyChanged
+                .add(move |_| *a_changes.borrow_mut() += 1);
+        }
+
+        bind_two_way(Rc::clone(&a), Rc::clone(&b), |v| *v, |v| *v);
+
+        a.borrow_mut().set(5);
+        assert_eq!(*a_changes.borrow(), 1);
+        assert_eq!(b.borrow().get(), 5);
+    }
+}
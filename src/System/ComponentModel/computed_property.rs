@@ -0,0 +1,196 @@
+use std::{
+    cell::RefCell,
+    ops::{Deref, DerefMut},
+    rc::{Rc, Weak},
+};
+
+use super::ObservableProperty;
+
+/// A property whose value is derived from one or more source [`ObservableProperty`]
+/// values and recomputed automatically whenever any of them changes.
+///
+/// `ComputedProperty` wraps an inner `ObservableProperty` (reachable through `Deref`,
+/// so `PropertyChanged`/`PropertyChanging` and `get()` work the same way), subscribes
+/// to each source's `PropertyChanged`, and re-runs the projection closure on every
+/// upstream notification. The recomputed result is only propagated — and only
+/// raises its own events — if it differs from the previous one, reusing
+/// `ObservableProperty`'s `Eq` short-circuit.
+///
+/// # Examples
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use mvvm::System::ComponentModel::{ComputedProperty, ObservableProperty};
+///
+/// let first_name = Rc::new(RefCell::new(ObservableProperty::new("Ada".to_string())));
+/// let last_name = Rc::new(RefCell::new(ObservableProperty::new("Lovelace".to_string())));
+///
+/// let full_name = ComputedProperty::derive_from2(
+///     Rc::clone(&first_name),
+///     Rc::clone(&last_name),
+///     |first, last| format!("{first} {last}"),
+/// );
+///
+/// assert_eq!(full_name.borrow().get(), "Ada Lovelace");
+///
+/// last_name.borrow_mut().set("King".to_string());
+///
+/// assert_eq!(full_name.borrow().get(), "Ada King");
+/// ```
+pub struct ComputedProperty<'a, T>
+where
+    T: Eq + Default + Clone,
+{
+    inner: ObservableProperty<'a, T>,
+}
+
+impl<'a, T> ComputedProperty<'a, T>
+where
+    T: Eq + Default + Clone + 'a,
+{
+    /// Creates a `ComputedProperty` that recomputes `f(&source)` every time `source`
+    /// raises `PropertyChanged`.
+    pub fn derive_from<S>(
+        source: Rc<RefCell<ObservableProperty<'a, S>>>,
+        f: impl Fn(&S) -> T + 'a,
+    ) -> Rc<RefCell<Self>>
+    where
+        S: Eq + Default + Clone + 'a,
+    {
+        let initial = f(&source.borrow().get());
+        let computed = Rc::new(RefCell::new(Self {
+            inner: ObservableProperty::new(initial),
+        }));
+
+        let weak: Weak<RefCell<Self>> = Rc::downgrade(&computed);
+        source.borrow_mut().PropertyChanged.add(move |p| {
+            if let Some(computed) = weak.upgrade() {
+                let value = f(&p.get());
+                computed.borrow_mut().inner.set(value);
+            }
+        });
+
+        computed
+    }
+
+    /// Creates a `ComputedProperty` that recomputes `f(&source1, &source2)` every time
+    /// either source raises `PropertyChanged`.
+    pub fn derive_from2<S1, S2>(
+        source1: Rc<RefCell<ObservableProperty<'a, S1>>>,
+        source2: Rc<RefCell<ObservableProperty<'a, S2>>>,
+        f: impl Fn(&S1, &S2) -> T + 'a,
+    ) -> Rc<RefCell<Self>>
+    where
+        S1: Eq + Default + Clone + 'a,
+        S2: Eq + Default + Clone + 'a,
+    {
+        let f = Rc::new(f);
+
+        let initial = f(&source1.borrow().get(), &source2.borrow().get());
+        let computed = Rc::new(RefCell::new(Self {
+            inner: ObservableProperty::new(initial),
+        }));
+
+        {
+            let weak: Weak<RefCell<Self>> = Rc::downgrade(&computed);
+            let source2 = Rc::clone(&source2);
+            let f = Rc::clone(&f);
+            source1.borrow_mut().PropertyChanged.add(move |p| {
+                if let Some(computed) = weak.upgrade() {
+                    let value = f(&p.get(), &source2.borrow().get());
+                    computed.borrow_mut().inner.set(value);
+                }
+            });
+        }
+        {
+            let weak: Weak<RefCell<Self>> = Rc::downgrade(&computed);
+            let source1 = Rc::clone(&source1);
+            source2.borrow_mut().PropertyChanged.add(move |p| {
+                if let Some(computed) = weak.upgrade() {
+                    let value = f(&source1.borrow().get(), &p.get());
+                    computed.borrow_mut().inner.set(value);
+                }
+            });
+        }
+
+        computed
+    }
+}
+
+impl<'a, T> Deref for ComputedProperty<'a, T>
+where
+    T: Eq + Default + Clone,
+{
+    type Target = ObservableProperty<'a, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, T> DerefMut for ComputedProperty<'a, T>
+where
+    T: Eq + Default + Clone,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    #[test]
+    fn test_derive_from() {
+        let source = Rc::new(RefCell::new(ObservableProperty::new(1)));
+        let doubled = ComputedProperty::derive_from(Rc::clone(&source), |v| v * 2);
+
+        assert_eq!(doubled.borrow().get(), 2);
+
+        source.borrow_mut().set(3);
+        assert_eq!(doubled.borrow().get(), 6);
+    }
+
+    #[test]
+    fn test_derive_from_skips_redundant_recompute() {
+        let source = Rc::new(RefCell::new(ObservableProperty::new(1)));
+        let doubled = ComputedProperty::derive_from(Rc::clone(&source), |v| v * 2);
+
+        let counter = Rc::new(RefCell::new(0));
+        let counter_clone = Rc::clone(&counter);
+        doubled
+            .borrow_mut()
+            .PropertyChanged
+            .add(move |_| *counter_clone.borrow_mut() += 1);
+
+        source.borrow_mut().set(1);
+        assert_eq!(*counter.borrow(), 0);
+
+        source.borrow_mut().set(4);
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[test]
+    fn test_derive_from2() {
+        let first_name = Rc::new(RefCell::new(ObservableProperty::new("Ada".to_string())));
+        let last_name = Rc::new(RefCell::new(ObservableProperty::new(
+            "Lovelace".to_string(),
+        )));
+
+        let full_name = ComputedProperty::derive_from2(
+            Rc::clone(&first_name),
+            Rc::clone(&last_name),
+            |first, last| format!("{first} {last}"),
+        );
+
+        assert_eq!(full_name.borrow().get(), "Ada Lovelace");
+
+        last_name.borrow_mut().set("King".to_string());
+        assert_eq!(full_name.borrow().get(), "Ada King");
+
+        first_name.borrow_mut().set("Augusta".to_string());
+        assert_eq!(full_name.borrow().get(), "Augusta King");
+    }
+}
@@ -0,0 +1,250 @@
+#![allow(non_snake_case)]
+
+use std::{
+    cell::{Ref, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use event_rs::Event;
+
+/// An interior-mutability sibling of [`ObservableProperty`](super::ObservableProperty)
+/// that notifies on change through a shared reference instead of `&mut self`.
+///
+/// This allows code that only holds `&ObservableCell` (e.g. behind an `Rc`) to still
+/// mutate the value and raise `PropertyChanging`/`PropertyChanged`.
+///
+/// # Type Parameters
+/// * `T`: The type of the property value. Must implement `Eq` and `Clone` so changes
+///   can be detected and the previous value preserved for comparison.
+///
+/// # Examples
+/// ```
+/// use mvvm::System::ComponentModel::ObservableCell;
+///
+/// let cell = ObservableCell::new(1);
+///
+/// cell.PropertyChanged.add(|c| assert_eq!(*c.borrow(), 2));
+///
+/// assert_eq!(cell.replace(2), 1);
+/// assert_eq!(*cell.borrow(), 2);
+/// ```
+#[derive(Default)]
+pub struct ObservableCell<'a, T>
+where
+    T: Eq + Default + Clone,
+{
+    /// The internal value of the property.
+    value: RefCell<T>,
+
+    /// Event invoked after the value has changed.
+    pub PropertyChanged: Event<'a, ObservableCell<'a, T>>,
+
+    /// Event invoked before the value changes.
+    pub PropertyChanging: Event<'a, ObservableCell<'a, T>>,
+
+    /// Pending [`when_eq`](Self::when_eq) waiters, checked after every successful change.
+    waiters: RefCell<Vec<(T, Waker)>>,
+}
+
+impl<'a, T> ObservableCell<'a, T>
+where
+    T: Eq + Default + Clone,
+{
+    /// Creates a new `ObservableCell` with the specified initial value.
+    ///
+    /// # Parameters
+    /// - `value`: The initial value of the property.
+    ///
+    /// # Examples
+    /// ```
+    /// use mvvm::System::ComponentModel::ObservableCell;
+    ///
+    /// let cell = ObservableCell::new(10);
+    /// assert_eq!(*cell.borrow(), 10);
+    /// ```
+    pub fn new(value: T) -> Self {
+        Self {
+            value: RefCell::new(value),
+            ..Default::default()
+        }
+    }
+
+    /// Invokes the `PropertyChanged` event.
+    fn OnPropertyChanged(&self) {
+        self.PropertyChanged.invoke(self)
+    }
+
+    /// Invokes the `PropertyChanging` event.
+    fn OnPropertyChanging(&self) {
+        self.PropertyChanging.invoke(self)
+    }
+
+    /// Borrows the current value of the property.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        self.value.borrow()
+    }
+
+    /// Replaces the value with `v`, returning the previous value.
+    ///
+    /// Raises `PropertyChanging` and `PropertyChanged` only if `v` differs from the
+    /// current value.
+    pub fn replace(&self, v: T) -> T {
+        if *self.value.borrow() == v {
+            return self.value.borrow().clone();
+        }
+
+        self.OnPropertyChanging();
+
+        let old = self.value.replace(v);
+
+        self.OnPropertyChanged();
+        self.wake_waiters();
+
+        old
+    }
+
+    /// Applies `f` to the value in place, raising `PropertyChanging` and
+    /// `PropertyChanged` only if `f` actually altered it.
+    ///
+    /// The change is detected by cloning the value, applying `f` to the clone, and
+    /// comparing it against the original before committing.
+    pub fn mutate(&self, f: impl FnOnce(&mut T)) {
+        let mut candidate = self.value.borrow().clone();
+        f(&mut candidate);
+
+        if *self.value.borrow() == candidate {
+            return;
+        }
+
+        self.OnPropertyChanging();
+
+        *self.value.borrow_mut() = candidate;
+
+        self.OnPropertyChanged();
+        self.wake_waiters();
+    }
+
+    /// Returns a [`Future`] that resolves the next time the value equals `target`.
+    ///
+    /// Resolves immediately if the value already equals `target` at the time of the
+    /// first poll.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::executor::block_on;
+    /// use mvvm::System::ComponentModel::ObservableCell;
+    ///
+    /// let cell = ObservableCell::new(0);
+    ///
+    /// cell.replace(1);
+    ///
+    /// block_on(cell.when_eq(1));
+    /// ```
+    pub fn when_eq<'b>(&'b self, target: T) -> Pin<Box<dyn Future<Output = ()> + 'b>>
+    where
+        'a: 'b,
+    {
+        Box::pin(WhenEq { cell: self, target })
+    }
+
+    /// Wakes and removes any pending [`when_eq`](Self::when_eq) waiters whose target
+    /// now matches the current value.
+    fn wake_waiters(&self) {
+        let value = self.value.borrow().clone();
+        self.waiters.borrow_mut().retain(|(target, waker)| {
+            if *target == value {
+                waker.wake_by_ref();
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+/// The [`Future`] returned by [`ObservableCell::when_eq`].
+struct WhenEq<'b, 'a, T>
+where
+    T: Eq + Default + Clone,
+{
+    cell: &'b ObservableCell<'a, T>,
+    target: T,
+}
+
+impl<'b, 'a, T> Future for WhenEq<'b, 'a, T>
+where
+    T: Eq + Default + Clone,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if *self.cell.value.borrow() == self.target {
+            return Poll::Ready(());
+        }
+
+        self.cell
+            .waiters
+            .borrow_mut()
+            .push((self.target.clone(), cx.waker().clone()));
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn test_replace() {
+        let counter = Rc::new(RefCell::new(0));
+        let cell = ObservableCell::new(1);
+
+        cell.PropertyChanging.add(|c| {
+            assert_eq!(*c.borrow(), 1);
+            *counter.borrow_mut() += 1;
+        });
+        cell.PropertyChanged.add(|c| {
+            assert_eq!(*c.borrow(), 2);
+            *counter.borrow_mut() += 1;
+        });
+
+        assert_eq!(cell.replace(1), 1);
+        assert_eq!(cell.replace(2), 1);
+        assert_eq!(*cell.borrow(), 2);
+
+        assert_eq!(*counter.borrow(), 2);
+    }
+
+    #[test]
+    fn test_mutate() {
+        let counter = Rc::new(RefCell::new(0));
+        let cell = ObservableCell::new(vec![1, 2]);
+
+        cell.PropertyChanged.add(|_| *counter.borrow_mut() += 1);
+
+        cell.mutate(|v| v.sort());
+        assert_eq!(*counter.borrow(), 0);
+
+        cell.mutate(|v| v.push(3));
+        assert_eq!(*cell.borrow(), vec![1, 2, 3]);
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[test]
+    fn test_when_eq() {
+        let cell = ObservableCell::new(0);
+
+        cell.replace(1);
+        block_on(cell.when_eq(1));
+
+        cell.mutate(|v| *v += 1);
+        block_on(cell.when_eq(2));
+    }
+}
@@ -1,6 +1,9 @@
 #![allow(non_snake_case)]
 
 use event_rs::Event;
+use futures::{channel::mpsc::UnboundedSender, Stream};
+
+use super::subscription::{SubscriptionList, SubscriptionToken};
 
 /// Represents a property that can be observed for changes.
 ///
@@ -40,11 +43,38 @@ where
 
     /// Event Invoked before the value changes.
     pub PropertyChanging: Event<'a, ObservableProperty<'a, T>>,
+
+    /// Senders for the async change streams handed out by [`get_changes`](Self::get_changes).
+    changes: Vec<UnboundedSender<(T, T)>>,
+
+    /// The value held just before the change currently being processed, if any.
+    ///
+    /// Populated for the duration of a `PropertyChanging`/`PropertyChanged` invocation
+    /// and cleared immediately afterwards. See [`previous_value`](Self::previous_value).
+    previous: Option<T>,
+
+    /// Handlers registered through [`subscribe_changed`](Self::subscribe_changed) and
+    /// [`subscribe_changed_once`](Self::subscribe_changed_once), run after
+    /// `PropertyChanged`.
+    ///
+    /// Kept separate from `PropertyChanged` because `event_rs::Event` offers no way to
+    /// remove a handler once added, which self-unsubscribing and token-scoped handlers
+    /// both require.
+    changed_subscriptions: SubscriptionList<'a, Self>,
+
+    /// State of the outermost active [`batch`](Self::batch) scope, if any.
+    ///
+    /// `Some((snapshot, dirty))` once a scope is open: `snapshot` is the value as it
+    /// was when the scope opened, captured exactly once, and `dirty` records whether
+    /// any `set` inside the scope actually changed the value. Keeping these separate
+    /// lets a burst of sets inside the scope settle back to the original value without
+    /// firing a notification, while still firing exactly one if the net value differs.
+    batch: Option<(T, bool)>,
 }
 
 impl<'a, T> ObservableProperty<'a, T>
 where
-    T: Eq + Default,
+    T: Eq + Default + Clone,
 {
     /// Creates a new `ObservableProperty` with the specified initial value.
     ///
@@ -71,9 +101,10 @@ where
         }
     }
 
-    /// Invokes the `PropertyChanged` event.
+    /// Invokes the `PropertyChanged` event, then runs the `changed_subscriptions`.
     fn OnPropertyChanged(&self) {
-        self.PropertyChanged.invoke(self)
+        self.PropertyChanged.invoke(self);
+        self.changed_subscriptions.invoke(self);
     }
 
     /// Invokes the `PropertyChanging` event.
@@ -89,17 +120,32 @@ where
     ///
     /// The `PropertyChanging` and `PropertyChanged` events are not raised
     /// if the current and new value for the target property are the same.
+    ///
+    /// While a [`batch`](Self::batch) scope is open, the value is updated immediately
+    /// but events are deferred until the scope closes.
     fn SetProperty(&mut self, value: T) -> bool {
         if self.value == value {
             return false;
         }
 
+        if let Some((_, dirty)) = &mut self.batch {
+            *dirty = true;
+            self.value = value;
+            return true;
+        }
+
+        self.previous = Some(self.value.clone());
+
         self.OnPropertyChanging();
 
-        self.value = value;
+        self.value = value.clone();
 
         self.OnPropertyChanged();
 
+        let old = self.previous.take().unwrap();
+        self.changes
+            .retain(|tx| tx.unbounded_send((old.clone(), value.clone())).is_ok());
+
         true
     }
 
@@ -108,6 +154,28 @@ where
         &self.value
     }
 
+    /// Returns the value the property held immediately before the change currently
+    /// being processed.
+    ///
+    /// Only `Some` while a `PropertyChanging` or `PropertyChanged` handler triggered by
+    /// this change is running; `None` at all other times.
+    ///
+    /// # Examples
+    /// ```
+    /// use mvvm::System::ComponentModel::ObservableProperty;
+    ///
+    /// let mut prop = ObservableProperty::new(1);
+    /// assert_eq!(prop.previous_value(), None);
+    ///
+    /// prop.PropertyChanged.add(|p| assert_eq!(p.previous_value(), Some(&1)));
+    /// prop.set(2);
+    ///
+    /// assert_eq!(prop.previous_value(), None);
+    /// ```
+    pub fn previous_value(&self) -> Option<&T> {
+        self.previous.as_ref()
+    }
+
     /// Compares the current and new values for a given property. If the value has changed,
     /// raises the `PropertyChanging` event, updates the property with the new value,
     /// then raises the `PropertyChanged` event.
@@ -121,15 +189,50 @@ where
     }
 
     /// Gets a clone of the current property value.
-    ///
-    /// Requires that `T` implements `Clone`.
-    pub fn get(&self) -> T
-    where
-        T: Clone,
-    {
+    pub fn get(&self) -> T {
         self.GetValue().clone()
     }
 
+    /// Returns a [`Stream`] that yields `(old, new)` tuples every time [`set`](Self::set)
+    /// successfully changes the value.
+    ///
+    /// Each call returns an independent stream backed by its own unbounded channel, so
+    /// multiple consumers can subscribe at the same time. A stream is dropped from the
+    /// internal sender list the next time a change is emitted after its receiver has
+    /// been closed.
+    ///
+    /// Inside a [`batch`](Self::batch) scope, `set` emits nothing; a single tuple of the
+    /// value before the scope opened and the final value is emitted when the outermost
+    /// scope closes, and none if the net value is unchanged.
+    ///
+    /// This complements the callback-based `PropertyChanged`/`PropertyChanging` events
+    /// for consumers that want to `.await` or combine changes with other async streams.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::{executor::block_on, StreamExt};
+    /// use mvvm::System::ComponentModel::ObservableProperty;
+    ///
+    /// let mut prop = ObservableProperty::new(1);
+    /// let mut changes = prop.get_changes();
+    ///
+    /// prop.set(2);
+    /// prop.set(2); // no-op, does not emit
+    /// prop.set(3);
+    /// drop(prop);
+    ///
+    /// block_on(async {
+    ///     assert_eq!(changes.next().await, Some((1, 2)));
+    ///     assert_eq!(changes.next().await, Some((2, 3)));
+    ///     assert_eq!(changes.next().await, None);
+    /// });
+    /// ```
+    pub fn get_changes(&mut self) -> impl Stream<Item = (T, T)> + use<T> {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        self.changes.push(tx);
+        rx
+    }
+
     /// Compares the current and new values for a given property. If the value has changed,
     /// raises the `PropertyChanging` event, updates the property with the new value,
     /// then raises the `PropertyChanged` event.
@@ -141,12 +244,95 @@ where
     pub fn set(&mut self, value: T) -> bool {
         self.SetValue(value)
     }
+
+    /// Subscribes `handler` to run after every successful change, like
+    /// `PropertyChanged.add`, but returns a token whose `Drop` detaches it.
+    ///
+    /// Use this instead of `PropertyChanged` when a listener's lifetime is scoped to
+    /// something shorter-lived than the property itself.
+    pub fn subscribe_changed(
+        &mut self,
+        handler: impl Fn(&Self) + 'a,
+    ) -> SubscriptionToken<'a, Self> {
+        self.changed_subscriptions.add_with_token(handler)
+    }
+
+    /// Subscribes a one-shot `handler` that is removed automatically the first time it
+    /// returns `true`, e.g. a one-time validator or temporary listener.
+    pub fn subscribe_changed_once(&mut self, handler: impl Fn(&Self) -> bool + 'a) {
+        self.changed_subscriptions.add_removable(handler);
+    }
+
+    /// Runs `f`, deferring `PropertyChanging`/`PropertyChanged` notifications for every
+    /// `set` it performs until `f` returns.
+    ///
+    /// At most one `PropertyChanging`/`PropertyChanged` pair is raised, carrying the
+    /// value as it was before the batch opened and the final value once it closes; none
+    /// is raised if the net value is unchanged. Nesting a `batch` call inside another
+    /// is safe: only the outermost scope captures the snapshot and fires the terminal
+    /// notification.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::{cell::RefCell, rc::Rc};
+    /// use mvvm::System::ComponentModel::ObservableProperty;
+    ///
+    /// let calls = Rc::new(RefCell::new(0));
+    /// let mut prop = ObservableProperty::new(1);
+    ///
+    /// let calls_clone = Rc::clone(&calls);
+    /// prop.PropertyChanged.add(move |p| {
+    ///     assert_eq!(p.previous_value(), Some(&1));
+    ///     assert_eq!(p.get(), 3);
+    ///     *calls_clone.borrow_mut() += 1;
+    /// });
+    ///
+    /// prop.batch(|p| {
+    ///     p.set(2);
+    ///     p.set(3);
+    /// });
+    ///
+    /// assert_eq!(*calls.borrow(), 1);
+    /// assert_eq!(prop.get(), 3);
+    /// ```
+    pub fn batch(&mut self, f: impl FnOnce(&mut Self)) {
+        let is_outer = self.batch.is_none();
+        if is_outer {
+            self.batch = Some((self.value.clone(), false));
+        }
+
+        f(self);
+
+        if !is_outer {
+            return;
+        }
+
+        let (snapshot, dirty) = self.batch.take().unwrap();
+        if !dirty || snapshot == self.value {
+            return;
+        }
+
+        let final_value = self.value.clone();
+
+        self.previous = Some(snapshot.clone());
+        self.value = snapshot.clone();
+        self.OnPropertyChanging();
+
+        self.value = final_value.clone();
+        self.OnPropertyChanged();
+
+        let old = self.previous.take().unwrap();
+        self.changes
+            .retain(|tx| tx.unbounded_send((old.clone(), final_value.clone())).is_ok());
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
+    use futures::{executor::block_on, StreamExt};
+
     use super::*;
 
     #[test]
@@ -172,4 +358,130 @@ mod tests {
 
         assert_eq!(*counter.borrow(), 0b101);
     }
+
+    #[test]
+    fn test_previous_value() {
+        let mut prop = ObservableProperty::new(1);
+
+        assert_eq!(prop.previous_value(), None);
+
+        prop.PropertyChanging
+            .add(|p| assert_eq!(p.previous_value(), Some(&1)));
+        prop.PropertyChanged
+            .add(|p| assert_eq!(p.previous_value(), Some(&1)));
+
+        prop.set(2);
+
+        assert_eq!(prop.previous_value(), None);
+    }
+
+    #[test]
+    fn test_get_changes() {
+        let mut prop = ObservableProperty::new(1);
+        let mut changes = prop.get_changes();
+
+        prop.set(2);
+        prop.set(2);
+        prop.set(3);
+        drop(prop);
+
+        block_on(async {
+            assert_eq!(changes.next().await, Some((1, 2)));
+            assert_eq!(changes.next().await, Some((2, 3)));
+            assert_eq!(changes.next().await, None);
+        });
+    }
+
+    #[test]
+    fn test_subscribe_changed_once() {
+        let mut prop = ObservableProperty::new(1);
+        let calls = Rc::new(RefCell::new(0));
+
+        let calls_clone = Rc::clone(&calls);
+        prop.subscribe_changed_once(move |_| {
+            *calls_clone.borrow_mut() += 1;
+            true
+        });
+
+        prop.set(2);
+        prop.set(3);
+
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[test]
+    fn test_subscribe_changed_token() {
+        let mut prop = ObservableProperty::new(1);
+        let calls = Rc::new(RefCell::new(0));
+
+        let calls_clone = Rc::clone(&calls);
+        let token = prop.subscribe_changed(move |_| *calls_clone.borrow_mut() += 1);
+
+        prop.set(2);
+        assert_eq!(*calls.borrow(), 1);
+
+        drop(token);
+
+        prop.set(3);
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[test]
+    fn test_batch_coalesces_into_one_notification() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut prop = ObservableProperty::new(1);
+
+        let calls_clone = Rc::clone(&calls);
+        prop.PropertyChanging
+            .add(move |p| calls_clone.borrow_mut().push(("changing", p.get())));
+        let calls_clone = Rc::clone(&calls);
+        prop.PropertyChanged
+            .add(move |p| calls_clone.borrow_mut().push(("changed", p.get())));
+
+        prop.batch(|p| {
+            p.set(2);
+            p.set(3);
+        });
+
+        assert_eq!(prop.get(), 3);
+        assert_eq!(*calls.borrow(), vec![("changing", 1), ("changed", 3)]);
+    }
+
+    #[test]
+    fn test_batch_fires_nothing_when_net_unchanged() {
+        let calls = Rc::new(RefCell::new(0));
+        let mut prop = ObservableProperty::new(1);
+
+        let calls_clone = Rc::clone(&calls);
+        prop.PropertyChanged
+            .add(move |_| *calls_clone.borrow_mut() += 1);
+
+        prop.batch(|p| {
+            p.set(2);
+            p.set(1);
+        });
+
+        assert_eq!(*calls.borrow(), 0);
+        assert_eq!(prop.get(), 1);
+    }
+
+    #[test]
+    fn test_nested_batch_fires_once() {
+        let calls = Rc::new(RefCell::new(0));
+        let mut prop = ObservableProperty::new(1);
+
+        let calls_clone = Rc::clone(&calls);
+        prop.PropertyChanged
+            .add(move |_| *calls_clone.borrow_mut() += 1);
+
+        prop.batch(|outer| {
+            outer.set(2);
+            outer.batch(|inner| {
+                inner.set(3);
+            });
+        });
+
+        assert_eq!(*calls.borrow(), 1);
+        assert_eq!(prop.get(), 3);
+    }
 }
@@ -0,0 +1,153 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+type Handler<'a, Owner> = Box<dyn Fn(&Owner) -> bool + 'a>;
+type Handlers<'a, Owner> = Rc<RefCell<Vec<Option<Handler<'a, Owner>>>>>;
+
+/// A list of handlers invoked with a reference to `Owner`, supporting self-removal.
+///
+/// `event_rs::Event` (used by `PropertyChanged`/`PropertyChanging`) has no way to
+/// remove a handler once added. `SubscriptionList` fills that gap: a handler can ask
+/// to be removed by returning `true`, and [`add_with_token`](Self::add_with_token)
+/// returns a [`SubscriptionToken`] whose `Drop` detaches the handler explicitly.
+///
+/// Handlers are stored in an indexed `Vec`. [`invoke`](Self::invoke) collects the
+/// indices to purge only after the full iteration completes, so a handler removing
+/// itself (or a token being dropped) mid-dispatch never shifts the indices other
+/// handlers are about to be visited at.
+pub struct SubscriptionList<'a, Owner> {
+    handlers: Handlers<'a, Owner>,
+}
+
+impl<'a, Owner> Default for SubscriptionList<'a, Owner> {
+    fn default() -> Self {
+        Self {
+            handlers: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+}
+
+impl<'a, Owner> SubscriptionList<'a, Owner> {
+    /// Creates an empty `SubscriptionList`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `handler`, which keeps running on every [`invoke`](Self::invoke) for
+    /// as long as this list lives.
+    pub fn add(&mut self, handler: impl Fn(&Owner) + 'a) {
+        self.add_removable(move |owner| {
+            handler(owner);
+            false
+        });
+    }
+
+    /// Registers `handler`, which is removed automatically the first time it returns
+    /// `true`.
+    pub fn add_removable(&mut self, handler: impl Fn(&Owner) -> bool + 'a) {
+        self.handlers.borrow_mut().push(Some(Box::new(handler)));
+    }
+
+    /// Registers `handler` and returns a [`SubscriptionToken`] whose `Drop` detaches
+    /// it.
+    pub fn add_with_token(
+        &mut self,
+        handler: impl Fn(&Owner) + 'a,
+    ) -> SubscriptionToken<'a, Owner> {
+        let index = {
+            let mut handlers = self.handlers.borrow_mut();
+            handlers.push(Some(Box::new(move |owner: &Owner| {
+                handler(owner);
+                false
+            })));
+            handlers.len() - 1
+        };
+
+        SubscriptionToken {
+            handlers: Rc::downgrade(&self.handlers),
+            index,
+        }
+    }
+
+    /// Invokes every live handler with `owner`, then purges any that asked to be
+    /// removed.
+    pub fn invoke(&self, owner: &Owner) {
+        let mut to_remove = Vec::new();
+
+        for (index, slot) in self.handlers.borrow().iter().enumerate() {
+            if let Some(handler) = slot {
+                if handler(owner) {
+                    to_remove.push(index);
+                }
+            }
+        }
+
+        let mut handlers = self.handlers.borrow_mut();
+        for index in to_remove {
+            handlers[index] = None;
+        }
+    }
+}
+
+/// A handle to a handler registered via [`SubscriptionList::add_with_token`]. Dropping
+/// it detaches the handler.
+pub struct SubscriptionToken<'a, Owner> {
+    handlers: Weak<RefCell<Vec<Option<Handler<'a, Owner>>>>>,
+    index: usize,
+}
+
+impl<'a, Owner> Drop for SubscriptionToken<'a, Owner> {
+    fn drop(&mut self) {
+        if let Some(handlers) = self.handlers.upgrade() {
+            if let Ok(mut handlers) = handlers.try_borrow_mut() {
+                if let Some(slot) = handlers.get_mut(self.index) {
+                    *slot = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    #[test]
+    fn test_add_removable() {
+        let mut list = SubscriptionList::new();
+        let calls = Rc::new(RefCell::new(0));
+
+        let calls_clone = Rc::clone(&calls);
+        list.add_removable(move |_: &()| {
+            *calls_clone.borrow_mut() += 1;
+            *calls_clone.borrow() == 2
+        });
+
+        list.invoke(&());
+        list.invoke(&());
+        list.invoke(&());
+
+        assert_eq!(*calls.borrow(), 2);
+    }
+
+    #[test]
+    fn test_add_with_token() {
+        let mut list = SubscriptionList::new();
+        let calls = Rc::new(RefCell::new(0));
+
+        let calls_clone = Rc::clone(&calls);
+        let token = list.add_with_token(move |_: &()| *calls_clone.borrow_mut() += 1);
+
+        list.invoke(&());
+        assert_eq!(*calls.borrow(), 1);
+
+        drop(token);
+
+        list.invoke(&());
+        assert_eq!(*calls.borrow(), 1);
+    }
+}